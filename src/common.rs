@@ -2,6 +2,24 @@ use std;
 
 
 
+/// Keeps only the filename-safe subset of `component` (letters, numbers,
+/// spaces, hyphens, underscores, and periods), trimmed of surrounding
+/// whitespace. Used to compute `sanitized_name`/`sanitized_author` and by the
+/// path-building functions so `name`/`author`/`version` are sanitized
+/// consistently everywhere.
+pub(crate) fn sanitize_filename_component(component: &str) -> Result<String, AppDirsError> {
+    let result: String = component
+        .trim()
+        .chars()
+        .filter(|&c| c.is_alphanumeric() || " -_.".contains(c))
+        .collect();
+    if result.is_empty() {
+        Err(AppDirsError::InvalidAppInfo)
+    } else {
+        Ok(result)
+    }
+}
+
 /// Trait for a struct that holds information about your app.
 ///
 /// # Caveats
@@ -10,13 +28,48 @@ use std;
 /// more human-readable if you stick to **letters, numbers, spaces, hyphens,
 /// underscores, and periods** for both properties.
 ///
-/// The `author` property is currently only used by Windows, as macOS and *nix
-/// specifications don't require it. Make sure your `name` string is unique!
+/// The `author` property is used by Windows, and by macOS when `qualifier`
+/// is set (to build a reverse-DNS bundle identifier); plain *nix specs don't
+/// require it. Make sure your `name` string is unique!
 pub trait AppInfo {
     /// Name of your app (e.g. "Hearthstone").
     fn name(&self) -> &str;
     /// Author of your app (e.g. "Blizzard").
     fn author(&self) -> &str;
+    /// `name()`, sanitized to the filename-safe subset of characters this
+    /// library uses when building paths. Lets you preview the on-disk
+    /// directory name, or validate your `AppInfo` up front, without calling
+    /// one of the path-building functions.
+    ///
+    /// Fails with `InvalidAppInfo` if nothing sanitizable remains.
+    fn sanitized_name(&self) -> Result<String, AppDirsError> {
+        sanitize_filename_component(self.name())
+    }
+    /// `author()`, sanitized the same way as `sanitized_name`.
+    fn sanitized_author(&self) -> Result<String, AppDirsError> {
+        sanitize_filename_component(self.author())
+    }
+    /// Version of your app (e.g. "1.2.0").
+    ///
+    /// When present, the version is appended as its own path segment after
+    /// `name`, so apps can keep per-version config/cache directories side by
+    /// side across upgrades (e.g. `~/.config/CoolApp/1.2.0`). It is sanitized
+    /// the same way as `name` and `author`.
+    fn version(&self) -> Option<&str> {
+        None
+    }
+    /// Reverse-DNS qualifier for your app (e.g. "com"), used on
+    /// macOS to build an Apple-style bundle identifier instead of a bare app
+    /// name.
+    ///
+    /// When present, the macOS backend joins `qualifier`, `author`, and
+    /// `name` with `.` (e.g. `com.SuperDev.CoolApp`) so directories follow
+    /// the convention used by `~/Library/Application Support/<bundle id>`.
+    /// Linux and Windows paths are unaffected. When absent, macOS keeps using
+    /// the bare `name`.
+    fn qualifier(&self) -> Option<&str> {
+        None
+    }
 }
 
 /// Struct that holds fixed information about your app.
@@ -25,7 +78,7 @@ pub trait AppInfo {
 ///
 /// ```
 /// use app_dirs::StaticAppInfo;
-/// const APP_INFO: StaticAppInfo = StaticAppInfo{name: "Awesome App", author: "Dedicated Dev"};
+/// const APP_INFO: StaticAppInfo = StaticAppInfo{name: "Awesome App", author: "Dedicated Dev", version: None, qualifier: None};
 /// ```
 ///
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -34,6 +87,11 @@ pub struct StaticAppInfo {
     pub name: &'static str,
     /// Author of your app (e.g. "Blizzard").
     pub author: &'static str,
+    /// Version of your app (e.g. "1.2.0"), if per-version paths are wanted.
+    pub version: Option<&'static str>,
+    /// Reverse-DNS qualifier (e.g. "com"), joined with `author` and `name`
+    /// to build a macOS bundle identifier instead of a bare app name.
+    pub qualifier: Option<&'static str>,
 }
 
 /// Struct that holds fixed information about your app for when it
@@ -42,8 +100,8 @@ pub struct StaticAppInfo {
 /// config file.
 ///
 /// ```
-/// use app_dirs::DynamicAppInfo;
-/// let APP_INFO = DynamicAppInfo{name: "Awesome App".to_string(), author: "Dedicated Dev".to_string()};
+/// use app_dirs::OwningAppInfo;
+/// let APP_INFO = OwningAppInfo{name: "Awesome App".to_string(), author: "Dedicated Dev".to_string(), version: None, qualifier: None};
 /// ```
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct OwningAppInfo {
@@ -51,6 +109,11 @@ pub struct OwningAppInfo {
     pub name: String,
     /// Author of your app (e.g. "Blizzard").
     pub author: String,
+    /// Version of your app (e.g. "1.2.0"), if per-version paths are wanted.
+    pub version: Option<String>,
+    /// Reverse-DNS qualifier (e.g. "com"), joined with `author` and `name`
+    /// to build a macOS bundle identifier instead of a bare app name.
+    pub qualifier: Option<String>,
 }
 
 impl AppInfo for StaticAppInfo {
@@ -61,6 +124,14 @@ impl AppInfo for StaticAppInfo {
     fn author(&self) -> &str {
         self.author
     }
+
+    fn version(&self) -> Option<&str> {
+        self.version
+    }
+
+    fn qualifier(&self) -> Option<&str> {
+        self.qualifier
+    }
 }
 
 impl AppInfo for OwningAppInfo {
@@ -71,6 +142,14 @@ impl AppInfo for OwningAppInfo {
     fn author(&self) -> &str {
         &self.author
     }
+
+    fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+
+    fn qualifier(&self) -> Option<&str> {
+        self.qualifier.as_deref()
+    }
 }
 
 
@@ -81,7 +160,7 @@ impl AppInfo for OwningAppInfo {
 ///
 /// Example: Windows does not supported shared application data and does not
 /// distinguish between config and data. Therefore, on Windows, all variants
-/// except `UserCache` return the same path.
+/// except `UserCache`, `UserLog`, and `UserRuntime` return the same path.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub enum AppDataType {
     /// User-specific app configuration data.
@@ -90,6 +169,17 @@ pub enum AppDataType {
     UserData,
     /// User-specific app cache data.
     UserCache,
+    /// User-specific app log data (e.g. `~/Library/Logs/<app>` on macOS,
+    /// `$XDG_STATE_HOME/<app>/logs` on *nix).
+    UserLog,
+    /// User-specific runtime data (e.g. `$XDG_RUNTIME_DIR/<app>` on *nix).
+    /// Falls back to `UserCache` on platforms or environments without a
+    /// dedicated runtime directory; only returns `NotSupported` when no
+    /// fallback is available and the caller explicitly asked for this type.
+    UserRuntime,
+    /// User-specific app state data (e.g. `$XDG_STATE_HOME/<app>`, default
+    /// `~/.local/state/<app>`, on *nix).
+    UserState,
     /// System-wide arbitrary app data.
     SharedData,
     /// System-wide app configuration data.
@@ -100,15 +190,12 @@ impl AppDataType {
     /// Returns `true` for non-user-specific data types.
     pub fn is_shared(&self) -> bool {
         use AppDataType::*;
-        match *self {
-            SharedData | SharedConfig => true,
-            _ => false,
-        }
+        matches!(*self, SharedData | SharedConfig)
     }
 }
 
-const ERR_NOT_SUPPORTED: &'static str = "App data directories not supported";
-const ERR_INVALID_APP_INFO: &'static str = "Invalid app name or author";
+const ERR_NOT_SUPPORTED: &str = "App data directories not supported";
+const ERR_INVALID_APP_INFO: &str = "Invalid app name or author";
 
 /// Error type for any `app_dirs` operation.
 #[derive(Debug)]
@@ -119,7 +206,7 @@ pub enum AppDirsError {
     /// (e.g. required environment variables don't exist).
     NotSupported,
     /// App info given to this library was invalid (e.g. app name or author
-    /// were empty).
+    /// were empty, or a `Some` version was empty).
     InvalidAppInfo,
 }
 
@@ -135,15 +222,7 @@ impl std::fmt::Display for AppDirsError {
 }
 
 impl std::error::Error for AppDirsError {
-    fn description(&self) -> &str {
-        use AppDirsError::*;
-        match *self {
-            Io(ref e) => e.description(),
-            NotSupported => "App data directories not supported",
-            InvalidAppInfo => "Invalid app name or author",
-        }
-    }
-    fn cause(&self) -> Option<&std::error::Error> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         use AppDirsError::*;
         match *self {
             Io(ref e) => Some(e),
@@ -158,3 +237,66 @@ impl From<std::io::Error> for AppDirsError {
         AppDirsError::Io(e)
     }
 }
+
+/// Trait for a platform convention used to resolve an [`AppDataType`] and
+/// [`AppInfo`] into a concrete path.
+///
+/// The crate's `get_app_root`/`app_root` functions are thin wrappers around
+/// whichever strategy matches the compile-target OS (`XdgStrategy` on *nix,
+/// `AppleStrategy` on macOS, `WindowsStrategy` on Windows). Implementing this
+/// trait yourself lets you inject a strategy backed by a temp directory or an
+/// explicit environment map for deterministic unit tests, or deliberately
+/// request one platform's path layout while running on another, via
+/// `get_app_root_with`.
+///
+/// [`AppDataType`]: enum.AppDataType.html
+/// [`AppInfo`]: trait.AppInfo.html
+pub trait AppStrategy {
+    /// Resolves the root path for `app_info`'s `ty` data under this
+    /// strategy's platform convention.
+    fn resolve(&self, ty: AppDataType, app_info: &dyn AppInfo) -> Result<std::path::PathBuf, AppDirsError>;
+}
+
+/// Appends `app_info.version()` (sanitized) as a trailing path segment of
+/// `path`, if present. Every `AppStrategy` implementation calls this last, so
+/// the version segment is sanitized and validated the same way everywhere.
+pub(crate) fn append_version(path: &mut std::path::PathBuf, app_info: &dyn AppInfo) -> Result<(), AppDirsError> {
+    if let Some(version) = app_info.version() {
+        path.push(sanitize_filename_component(version)?);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    struct TestApp(Option<&'static str>);
+    impl AppInfo for TestApp {
+        fn name(&self) -> &str { "App" }
+        fn author(&self) -> &str { "Author" }
+        fn version(&self) -> Option<&str> { self.0 }
+    }
+
+    #[test]
+    fn append_version_is_a_noop_when_absent() {
+        let mut path = PathBuf::from("/base/App");
+        append_version(&mut path, &TestApp(None)).unwrap();
+        assert_eq!(path, PathBuf::from("/base/App"));
+    }
+
+    #[test]
+    fn append_version_appends_the_sanitized_version() {
+        let mut path = PathBuf::from("/base/App");
+        append_version(&mut path, &TestApp(Some(" 1.2.0 "))).unwrap();
+        assert_eq!(path, PathBuf::from("/base/App/1.2.0"));
+    }
+
+    #[test]
+    fn append_version_rejects_an_empty_version() {
+        let mut path = PathBuf::from("/base/App");
+        let err = append_version(&mut path, &TestApp(Some(""))).unwrap_err();
+        assert!(matches!(err, AppDirsError::InvalidAppInfo));
+    }
+}
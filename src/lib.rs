@@ -0,0 +1,80 @@
+//! Put your app's data in the right place on every platform.
+//!
+//! See [`AppInfo`](trait.AppInfo.html) and [`AppDataType`](enum.AppDataType.html)
+//! to get started, or [`AppStrategy`](trait.AppStrategy.html) to control
+//! which platform convention is used.
+
+use std::fs;
+use std::path::PathBuf;
+
+mod common;
+mod macos;
+mod unix;
+mod windows;
+
+pub use common::*;
+pub use macos::AppleStrategy;
+pub use unix::XdgStrategy;
+pub use windows::WindowsStrategy;
+
+#[cfg(target_os = "macos")]
+fn default_strategy() -> Result<Box<dyn AppStrategy>, AppDirsError> {
+    Ok(Box::new(AppleStrategy::new()?))
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+fn default_strategy() -> Result<Box<dyn AppStrategy>, AppDirsError> {
+    Ok(Box::new(XdgStrategy::new()?))
+}
+
+#[cfg(windows)]
+fn default_strategy() -> Result<Box<dyn AppStrategy>, AppDirsError> {
+    Ok(Box::new(WindowsStrategy::new()?))
+}
+
+/// Returns the root path for storing `app_info`'s `t` data, using the
+/// compile-target's default platform convention. Does not create the
+/// directory; see `app_root` for that.
+pub fn get_app_root(t: AppDataType, app_info: &dyn AppInfo) -> Result<PathBuf, AppDirsError> {
+    default_strategy()?.resolve(t, app_info)
+}
+
+/// Like `get_app_root`, but also creates the directory (and any missing
+/// parents) if it doesn't already exist.
+pub fn app_root(t: AppDataType, app_info: &dyn AppInfo) -> Result<PathBuf, AppDirsError> {
+    let path = get_app_root(t, app_info)?;
+    fs::create_dir_all(&path)?;
+    Ok(path)
+}
+
+/// Like `get_app_root`, but resolves the path through an explicit `strategy`
+/// instead of the compile-target's default. Useful for deterministic unit
+/// tests (inject a strategy backed by a temp dir or an explicit environment
+/// map) or for deliberately emitting another platform's path layout.
+pub fn get_app_root_with(strategy: &dyn AppStrategy, t: AppDataType, app_info: &dyn AppInfo) -> Result<PathBuf, AppDirsError> {
+    strategy.resolve(t, app_info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    struct TestApp;
+    impl AppInfo for TestApp {
+        fn name(&self) -> &str { "CoolApp" }
+        fn author(&self) -> &str { "Dedicated Dev" }
+    }
+
+    #[test]
+    fn get_app_root_with_injects_a_deterministic_strategy() {
+        let mut env = HashMap::new();
+        env.insert("HOME".to_string(), "/home/tester".to_string());
+        env.insert("XDG_CONFIG_HOME".to_string(), "/home/tester/.config".to_string());
+        let strategy = XdgStrategy::from_env(|key| env.get(key).cloned()).unwrap();
+
+        let path = get_app_root_with(&strategy, AppDataType::UserConfig, &TestApp).unwrap();
+
+        assert_eq!(path, PathBuf::from("/home/tester/.config/CoolApp"));
+    }
+}
@@ -0,0 +1,91 @@
+use std::env;
+use std::path::PathBuf;
+
+use common::{self, AppDataType, AppDirsError, AppInfo, AppStrategy};
+
+/// Apple's directory convention, used by default on macOS.
+pub struct AppleStrategy {
+    home: PathBuf,
+}
+
+impl AppleStrategy {
+    /// Builds a strategy from the current process's `$HOME`.
+    pub fn new() -> Result<Self, AppDirsError> {
+        env::var("HOME").map(|home| AppleStrategy { home: PathBuf::from(home) }).map_err(|_| AppDirsError::NotSupported)
+    }
+
+    /// Builds a strategy rooted at an explicit home directory, e.g. a temp
+    /// dir fixture, for deterministic unit tests.
+    pub fn from_home(home: PathBuf) -> Self {
+        AppleStrategy { home }
+    }
+}
+
+impl AppStrategy for AppleStrategy {
+    fn resolve(&self, t: AppDataType, app_info: &dyn AppInfo) -> Result<PathBuf, AppDirsError> {
+        let mut path = match t {
+            AppDataType::UserConfig | AppDataType::UserData | AppDataType::UserState => {
+                self.home.join("Library/Application Support")
+            }
+            AppDataType::UserCache | AppDataType::UserRuntime => self.home.join("Library/Caches"),
+            AppDataType::UserLog => self.home.join("Library/Logs"),
+            AppDataType::SharedData | AppDataType::SharedConfig => PathBuf::from("/Library/Application Support"),
+        };
+        path.push(leaf_name(app_info)?);
+        common::append_version(&mut path, app_info)?;
+        Ok(path)
+    }
+}
+
+/// The leaf directory name for `app_info`: a reverse-DNS bundle identifier
+/// (`qualifier.author.name`) when a `qualifier` is set, or the bare
+/// sanitized `name` otherwise. Spaces within each joined component are
+/// replaced with hyphens (e.g. "Foo Corp" -> "Foo-Corp") before joining with
+/// `.`, matching Apple's bundle-identifier convention.
+fn leaf_name(app_info: &dyn AppInfo) -> Result<String, AppDirsError> {
+    match app_info.qualifier() {
+        Some(qualifier) => {
+            let qualifier = bundle_component(qualifier)?;
+            let author = bundle_component(app_info.author())?;
+            let name = bundle_component(app_info.name())?;
+            Ok(format!("{}.{}.{}", qualifier, author, name))
+        }
+        None => app_info.sanitized_name(),
+    }
+}
+
+fn bundle_component(component: &str) -> Result<String, AppDirsError> {
+    common::sanitize_filename_component(&component.replace(' ', "-"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestApp { name: &'static str, author: &'static str, qualifier: Option<&'static str> }
+    impl AppInfo for TestApp {
+        fn name(&self) -> &str { self.name }
+        fn author(&self) -> &str { self.author }
+        fn qualifier(&self) -> Option<&str> { self.qualifier }
+    }
+
+    #[test]
+    fn resolve_uses_the_bare_name_without_a_qualifier() {
+        let strategy = AppleStrategy::from_home(PathBuf::from("/Users/tester"));
+        let app = TestApp { name: "CoolApp", author: "Dedicated Dev", qualifier: None };
+
+        let path = strategy.resolve(AppDataType::UserConfig, &app).unwrap();
+
+        assert_eq!(path, PathBuf::from("/Users/tester/Library/Application Support/CoolApp"));
+    }
+
+    #[test]
+    fn resolve_joins_qualifier_author_and_name_into_a_bundle_id() {
+        let strategy = AppleStrategy::from_home(PathBuf::from("/Users/tester"));
+        let app = TestApp { name: "Bar App", author: "Foo Corp", qualifier: Some("com") };
+
+        let path = strategy.resolve(AppDataType::UserConfig, &app).unwrap();
+
+        assert_eq!(path, PathBuf::from("/Users/tester/Library/Application Support/com.Foo-Corp.Bar-App"));
+    }
+}
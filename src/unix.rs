@@ -0,0 +1,146 @@
+use std::env;
+use std::path::PathBuf;
+
+use common::{self, AppDataType, AppDirsError, AppInfo, AppStrategy};
+
+/// Platform convention following the XDG Base Directory Specification, used
+/// by default on Linux and other non-Apple Unix-likes.
+pub struct XdgStrategy {
+    home: PathBuf,
+    xdg_config_home: Option<PathBuf>,
+    xdg_cache_home: Option<PathBuf>,
+    xdg_data_home: Option<PathBuf>,
+    xdg_state_home: Option<PathBuf>,
+    xdg_runtime_dir: Option<PathBuf>,
+}
+
+impl XdgStrategy {
+    /// Builds a strategy from the current process's environment (`$HOME`
+    /// and the `$XDG_*` variables).
+    pub fn new() -> Result<Self, AppDirsError> {
+        Self::from_env(|key| env::var(key).ok())
+    }
+
+    /// Builds a strategy from an arbitrary variable lookup function, e.g. a
+    /// closure backed by a `HashMap` or a temp-dir fixture, for deterministic
+    /// unit tests instead of reading process-wide environment variables.
+    pub fn from_env<F: Fn(&str) -> Option<String>>(lookup: F) -> Result<Self, AppDirsError> {
+        let home = lookup("HOME").map(PathBuf::from).ok_or(AppDirsError::NotSupported)?;
+        Ok(XdgStrategy {
+            home,
+            xdg_config_home: lookup("XDG_CONFIG_HOME").map(PathBuf::from),
+            xdg_cache_home: lookup("XDG_CACHE_HOME").map(PathBuf::from),
+            xdg_data_home: lookup("XDG_DATA_HOME").map(PathBuf::from),
+            xdg_state_home: lookup("XDG_STATE_HOME").map(PathBuf::from),
+            xdg_runtime_dir: lookup("XDG_RUNTIME_DIR").map(PathBuf::from),
+        })
+    }
+}
+
+impl AppStrategy for XdgStrategy {
+    fn resolve(&self, t: AppDataType, app_info: &dyn AppInfo) -> Result<PathBuf, AppDirsError> {
+        let mut path = match t {
+            AppDataType::UserConfig => self.xdg_config_home.clone().unwrap_or_else(|| self.home.join(".config")),
+            AppDataType::UserData => self.xdg_data_home.clone().unwrap_or_else(|| self.home.join(".local/share")),
+            AppDataType::UserCache => self.xdg_cache_home.clone().unwrap_or_else(|| self.home.join(".cache")),
+            AppDataType::UserLog | AppDataType::UserState => {
+                self.xdg_state_home.clone().unwrap_or_else(|| self.home.join(".local/state"))
+            }
+            AppDataType::UserRuntime => self
+                .xdg_runtime_dir
+                .clone()
+                .or_else(|| self.xdg_cache_home.clone())
+                .unwrap_or_else(|| self.home.join(".cache")),
+            AppDataType::SharedData => PathBuf::from("/usr/local/share"),
+            AppDataType::SharedConfig => PathBuf::from("/etc/xdg"),
+        };
+        path.push(app_info.sanitized_name()?);
+        if t == AppDataType::UserLog {
+            path.push("logs");
+        }
+        common::append_version(&mut path, app_info)?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestApp(Option<&'static str>);
+    impl AppInfo for TestApp {
+        fn name(&self) -> &str { "CoolApp" }
+        fn author(&self) -> &str { "Dedicated Dev" }
+        fn version(&self) -> Option<&str> { self.0 }
+    }
+
+    fn strategy() -> XdgStrategy {
+        XdgStrategy::from_env(|key| match key {
+            "HOME" => Some("/home/tester".to_string()),
+            _ => None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn resolve_appends_the_version_after_the_app_name() {
+        let path = strategy().resolve(AppDataType::UserConfig, &TestApp(Some("1.2.0"))).unwrap();
+        assert_eq!(path, PathBuf::from("/home/tester/.config/CoolApp/1.2.0"));
+    }
+
+    #[test]
+    fn resolve_omits_the_version_segment_when_absent() {
+        let path = strategy().resolve(AppDataType::UserConfig, &TestApp(None)).unwrap();
+        assert_eq!(path, PathBuf::from("/home/tester/.config/CoolApp"));
+    }
+
+    #[test]
+    fn resolve_rejects_an_empty_version() {
+        let err = strategy().resolve(AppDataType::UserConfig, &TestApp(Some(""))).unwrap_err();
+        assert!(matches!(err, AppDirsError::InvalidAppInfo));
+    }
+
+    #[test]
+    fn user_log_nests_under_the_state_dir_with_a_logs_suffix() {
+        let path = strategy().resolve(AppDataType::UserLog, &TestApp(None)).unwrap();
+        assert_eq!(path, PathBuf::from("/home/tester/.local/state/CoolApp/logs"));
+    }
+
+    #[test]
+    fn user_runtime_falls_back_to_cache_without_xdg_runtime_dir() {
+        let path = strategy().resolve(AppDataType::UserRuntime, &TestApp(None)).unwrap();
+        assert_eq!(path, PathBuf::from("/home/tester/.cache/CoolApp"));
+    }
+
+    #[test]
+    fn user_runtime_prefers_xdg_runtime_dir_when_set() {
+        let strategy = XdgStrategy::from_env(|key| match key {
+            "HOME" => Some("/home/tester".to_string()),
+            "XDG_RUNTIME_DIR" => Some("/run/user/1000".to_string()),
+            _ => None,
+        })
+        .unwrap();
+
+        let path = strategy.resolve(AppDataType::UserRuntime, &TestApp(None)).unwrap();
+
+        assert_eq!(path, PathBuf::from("/run/user/1000/CoolApp"));
+    }
+
+    struct NamedApp(&'static str);
+    impl AppInfo for NamedApp {
+        fn name(&self) -> &str { self.0 }
+        fn author(&self) -> &str { "Dedicated Dev" }
+    }
+
+    #[test]
+    fn resolve_sanitizes_an_unsafe_app_name() {
+        let path = strategy().resolve(AppDataType::UserConfig, &NamedApp("Cool/App!")).unwrap();
+        assert_eq!(path, PathBuf::from("/home/tester/.config/CoolApp"));
+    }
+
+    #[test]
+    fn resolve_rejects_a_name_that_sanitizes_to_empty() {
+        let err = strategy().resolve(AppDataType::UserConfig, &NamedApp("///")).unwrap_err();
+        assert!(matches!(err, AppDirsError::InvalidAppInfo));
+    }
+}
@@ -0,0 +1,110 @@
+use std::env;
+use std::path::PathBuf;
+
+use common::{self, AppDataType, AppDirsError, AppInfo, AppStrategy};
+
+/// Windows' directory convention, used by default on Windows.
+///
+/// Windows doesn't support shared application data and doesn't distinguish
+/// between config and data, so most variants resolve to the same `%APPDATA%`
+/// tree; only `UserCache`, `UserLog`, and `UserRuntime` (Windows has no
+/// runtime-dir concept either) use `%LOCALAPPDATA%` instead.
+pub struct WindowsStrategy {
+    appdata: Option<PathBuf>,
+    localappdata: Option<PathBuf>,
+}
+
+impl WindowsStrategy {
+    /// Builds a strategy from the current process's `%APPDATA%` and
+    /// `%LOCALAPPDATA%`.
+    pub fn new() -> Result<Self, AppDirsError> {
+        Self::from_env(|key| env::var(key).ok())
+    }
+
+    /// Builds a strategy from an arbitrary variable lookup function, for
+    /// deterministic unit tests instead of reading process-wide environment
+    /// variables.
+    pub fn from_env<F: Fn(&str) -> Option<String>>(lookup: F) -> Result<Self, AppDirsError> {
+        let appdata = lookup("APPDATA").map(PathBuf::from);
+        let localappdata = lookup("LOCALAPPDATA").map(PathBuf::from);
+        if appdata.is_none() && localappdata.is_none() {
+            return Err(AppDirsError::NotSupported);
+        }
+        Ok(WindowsStrategy { appdata, localappdata })
+    }
+}
+
+impl AppStrategy for WindowsStrategy {
+    fn resolve(&self, t: AppDataType, app_info: &dyn AppInfo) -> Result<PathBuf, AppDirsError> {
+        let base = match t {
+            AppDataType::UserCache | AppDataType::UserLog | AppDataType::UserRuntime => {
+                self.localappdata.clone().or_else(|| self.appdata.clone())
+            }
+            _ => self.appdata.clone().or_else(|| self.localappdata.clone()),
+        };
+        let mut path = base.ok_or(AppDirsError::NotSupported)?;
+        path.push(app_info.sanitized_author()?);
+        path.push(app_info.sanitized_name()?);
+        common::append_version(&mut path, app_info)?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestApp { name: &'static str, author: &'static str, version: Option<&'static str> }
+    impl AppInfo for TestApp {
+        fn name(&self) -> &str { self.name }
+        fn author(&self) -> &str { self.author }
+        fn version(&self) -> Option<&str> { self.version }
+    }
+
+    fn app() -> TestApp {
+        TestApp { name: "CoolApp", author: "Dedicated Dev", version: None }
+    }
+
+    fn strategy() -> WindowsStrategy {
+        WindowsStrategy::from_env(|key| match key {
+            "APPDATA" => Some(r"C:\Users\tester\AppData\Roaming".to_string()),
+            "LOCALAPPDATA" => Some(r"C:\Users\tester\AppData\Local".to_string()),
+            _ => None,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn default_variants_use_appdata() {
+        let expected = PathBuf::from(r"C:\Users\tester\AppData\Roaming").join("Dedicated Dev").join("CoolApp");
+        for t in [AppDataType::UserConfig, AppDataType::UserData, AppDataType::UserState, AppDataType::SharedData, AppDataType::SharedConfig] {
+            let path = strategy().resolve(t, &app()).unwrap();
+            assert_eq!(path, expected);
+        }
+    }
+
+    #[test]
+    fn cache_log_and_runtime_use_localappdata() {
+        let expected = PathBuf::from(r"C:\Users\tester\AppData\Local").join("Dedicated Dev").join("CoolApp");
+        for t in [AppDataType::UserCache, AppDataType::UserLog, AppDataType::UserRuntime] {
+            let path = strategy().resolve(t, &app()).unwrap();
+            assert_eq!(path, expected);
+        }
+    }
+
+    #[test]
+    fn resolve_appends_the_version_after_the_app_name() {
+        let app = TestApp { version: Some("1.2.0"), ..app() };
+        let path = strategy().resolve(AppDataType::UserConfig, &app).unwrap();
+        let expected = PathBuf::from(r"C:\Users\tester\AppData\Roaming").join("Dedicated Dev").join("CoolApp").join("1.2.0");
+        assert_eq!(path, expected);
+    }
+
+    #[test]
+    fn resolve_sanitizes_author_and_name() {
+        let app = TestApp { name: "Cool/App!", author: "Dedicated/Dev!", version: None };
+        let path = strategy().resolve(AppDataType::UserConfig, &app).unwrap();
+        let expected = PathBuf::from(r"C:\Users\tester\AppData\Roaming").join("DedicatedDev").join("CoolApp");
+        assert_eq!(path, expected);
+    }
+}